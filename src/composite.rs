@@ -0,0 +1,139 @@
+use super::prelude::*;
+use core::fmt;
+
+/// combining logic for a [`Composite`] controller
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Combinator {
+    /// the composite is in state `B` only when every child is in state `B` (logical AND)
+    #[default]
+    All,
+    /// the composite is in state `B` when at least one child is in state `B` (logical OR)
+    Any,
+}
+
+/// interlock controller that reduces several child [`BangBang`] controllers into one effective
+/// state using boolean logic—e.g. a furnace that should only run if the thermostat calls for
+/// heat *and* a safety limit permits it
+///
+/// `state()` is a read-through reduction of the children's own states, combined according to
+/// `combinator`. `set()`/`bang()` forward the requested transition to every child in turn,
+/// stopping at (and returning) the first child's error, so a child's own handler-failure or
+/// constraint semantics are what ultimately accepts or rejects the composite's transition.
+///
+/// Children are held in a fixed-size array—no heap allocation—so the number of children is part
+/// of the type, just as with any other `#![no_std]`-friendly fixed-capacity container.
+///
+/// # Example
+/// ```
+/// use bangbang::prelude::*;
+///
+/// fn example() -> Result<(), BangBangError> {
+///     let mut thermostat = OnOff::new(true, None, None);
+///     let mut safety_limit = OnOff::new(true, None, None);
+///
+///     // the furnace may only run if both conditions call for it
+///     let mut furnace =
+///         Composite::new([&mut thermostat, &mut safety_limit], Combinator::All);
+///     assert!(furnace.is_on());
+///
+///     // the safety limit alone trips...
+///     furnace.children_mut()[1].bang()?;
+///     // ...so the AND-combined furnace interlock is no longer satisfied
+///     assert!(furnace.is_off());
+///
+///     Ok(())
+/// }
+///
+/// example();
+/// ```
+pub struct Composite<'a, const N: usize> {
+    children: [&'a mut dyn BangBang; N],
+    combinator: Combinator,
+}
+
+impl<const N: usize> fmt::Debug for Composite<'_, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Composite {{ state: {:?}, combinator: {:?} }}",
+            self.state(),
+            self.combinator
+        )
+    }
+}
+
+impl<const N: usize> BangBang for Composite<'_, N> {
+    fn state(&self) -> BangBangState {
+        let reduced = match self.combinator {
+            Combinator::All => self
+                .children
+                .iter()
+                .all(|child| child.state() == BangBangState::B),
+            Combinator::Any => self
+                .children
+                .iter()
+                .any(|child| child.state() == BangBangState::B),
+        };
+        reduced.into()
+    }
+
+    fn set(&mut self, new_state: BangBangState) -> Result<(), BangBangError> {
+        for child in &mut self.children {
+            child.set(new_state)?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a, const N: usize> Composite<'a, N> {
+    /// builds a composite interlock from `children`, reduced to a single effective state by
+    /// `combinator`
+    /// ```
+    /// use bangbang::prelude::*;
+    ///
+    /// let mut a = OnOff::new(true, None, None);
+    /// let mut b = OnOff::new(false, None, None);
+    ///
+    /// // `Any` (logical OR): on if at least one child is on
+    /// let composite = Composite::new([&mut a, &mut b], Combinator::Any);
+    /// assert!(composite.is_on());
+    /// ```
+    pub fn new(children: [&'a mut dyn BangBang; N], combinator: Combinator) -> Self {
+        Self {
+            children,
+            combinator,
+        }
+    }
+
+    /// gives direct mutable access to the child controllers, e.g. to drive one independently of
+    /// the composite's own `set()`/`bang()`
+    pub fn children_mut(&mut self) -> &mut [&'a mut dyn BangBang; N] {
+        &mut self.children
+    }
+
+    /// convienence method for checking if the composite is in the `on` state
+    /// ```
+    /// use bangbang::prelude::*;
+    ///
+    /// let mut a = OnOff::new(true, None, None);
+    /// let mut b = OnOff::new(true, None, None);
+    /// let composite = Composite::new([&mut a, &mut b], Combinator::All);
+    /// assert!(composite.is_on());
+    /// ```
+    pub fn is_on(&self) -> bool {
+        self.state() == BangBangState::B
+    }
+
+    /// convienence method for checking if the composite is in the `off` state
+    /// ```
+    /// use bangbang::prelude::*;
+    ///
+    /// let mut a = OnOff::new(true, None, None);
+    /// let mut b = OnOff::new(false, None, None);
+    /// let composite = Composite::new([&mut a, &mut b], Combinator::All);
+    /// assert!(composite.is_off());
+    /// ```
+    pub fn is_off(&self) -> bool {
+        self.state() == BangBangState::A
+    }
+}
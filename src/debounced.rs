@@ -0,0 +1,132 @@
+use super::prelude::*;
+use core::fmt;
+
+/// monotonic clock closure, returning milliseconds, used to time the minimum dwell periods
+type Clock = dyn FnMut() -> u64 + Sync + Send;
+
+/// wraps a [`BangBang`] controller with a minimum on-time and minimum off-time, rejecting any
+/// state change attempted before the controller has dwelt in its current state for long enough
+///
+/// This is the anti-short-cycle guard many real bang-bang actuators (compressors, relays) need:
+/// cycling faster than their rated minimum on/off time risks premature failure. `min_on` and
+/// `min_off` are measured in milliseconds, as reported by the caller-supplied `clock`.
+///
+/// # Example
+/// ```
+/// use bangbang::prelude::*;
+///
+/// fn example() -> Result<(), BangBangError> {
+///     use std::sync::atomic::{AtomicU64, Ordering};
+///     use std::sync::Arc;
+///
+///     let now = Arc::new(AtomicU64::new(0));
+///     let now_for_clock = Arc::clone(&now);
+///     let mut clock = move || now_for_clock.load(Ordering::Relaxed);
+///
+///     // an on/off controller wrapped with a 1000ms minimum on-time and off-time
+///     let on_off = OnOff::new(false, None, None);
+///     let mut debounced = Debounced::new(on_off, 1000, 1000, &mut clock);
+///
+///     // the freshly-created controller is considered to have just transitioned, so an
+///     // immediate flip is rejected
+///     assert!(debounced.bang().is_err());
+///     assert!(debounced.is_off());
+///
+///     // once enough time has passed, the transition is allowed
+///     now.store(1000, Ordering::Relaxed);
+///     assert!(debounced.bang().is_ok());
+///     assert!(debounced.is_on());
+///
+///     Ok(())
+/// }
+///
+/// example();
+/// ```
+pub struct Debounced<'a, C: BangBang> {
+    inner: C,
+    min_on: u64,
+    min_off: u64,
+    last_transition: u64,
+    clock: &'a mut Clock,
+}
+
+impl<C: BangBang> fmt::Debug for Debounced<'_, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Debounced {{ state: {:?}, min_on: {}, min_off: {}, last_transition: {} }}",
+            self.inner.state(),
+            self.min_on,
+            self.min_off,
+            self.last_transition,
+        )
+    }
+}
+
+impl<C: BangBang> BangBang for Debounced<'_, C> {
+    fn state(&self) -> BangBangState {
+        self.inner.state()
+    }
+
+    fn set(&mut self, new_state: BangBangState) -> Result<(), BangBangError> {
+        let current_state = self.inner.state();
+
+        if new_state == current_state {
+            return self.inner.set(new_state);
+        }
+
+        let elapsed = (self.clock)().wrapping_sub(self.last_transition);
+        let constrained = match current_state {
+            BangBangState::B => elapsed < self.min_on,
+            BangBangState::A => elapsed < self.min_off,
+        };
+
+        if constrained {
+            return Err(BangBangError::StateChangeTemporarilyConstrained {
+                from: current_state,
+                to: new_state,
+                code: 0,
+            });
+        }
+
+        self.inner.set(new_state)?;
+        self.last_transition = (self.clock)();
+        Ok(())
+    }
+}
+
+impl<'a, C: BangBang> Debounced<'a, C> {
+    /// wraps `inner` with a minimum on-time and minimum off-time, both in milliseconds as
+    /// reported by `clock`
+    ///
+    /// The wrapped controller is treated as having just transitioned at the moment it is
+    /// constructed, so a change attempted immediately after construction is rejected until
+    /// `clock` reports enough elapsed time.
+    /// ```
+    /// use bangbang::prelude::*;
+    ///
+    /// let mut clock = || 0u64;
+    /// let debounced = Debounced::new(OnOff::new(false, None, None), 500, 500, &mut clock);
+    /// assert!(debounced.is_off());
+    /// ```
+    pub fn new(inner: C, min_on: u64, min_off: u64, clock: &'a mut Clock) -> Self {
+        let last_transition = clock();
+        Self {
+            inner,
+            min_on,
+            min_off,
+            last_transition,
+            clock,
+        }
+    }
+
+    /// convienence method for checking if the wrapped controller is in the `on` state
+    pub fn is_on(&self) -> bool {
+        self.state() == BangBangState::B
+    }
+
+    /// convienence method for checking if the wrapped controller is in the `off` state
+    pub fn is_off(&self) -> bool {
+        self.state() == BangBangState::A
+    }
+}
@@ -0,0 +1,247 @@
+use super::prelude::*;
+use core::fmt;
+
+#[cfg(feature = "log")]
+use log::{debug, trace};
+
+/// handler method to be called on a state change, reporting failure as a ready-made
+/// [`BangBangError`]
+#[cfg(not(feature = "boxed-source"))]
+type StateChangeHander = dyn FnMut() -> Result<(), BangBangError> + Sync + Send;
+
+/// handler method to be called on a state change, reporting failure as its own boxed domain
+/// error—`set()` wraps it into [`BangBangError::StateChangeHandlerFailed`] automatically,
+/// preserving it as that error's [`source()`](core::error::Error::source)
+#[cfg(feature = "boxed-source")]
+type StateChangeHander = dyn FnMut() -> Result<(), super::BoxedSource> + Sync + Send;
+
+/// direction of control for a [`Hysteresis`] controller
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum HysteresisDirection {
+    /// state `B` (on) is entered when the measurement falls to or below `lower`, and state
+    /// `A` (off) is entered when the measurement rises to or above `upper`—as with a furnace
+    #[default]
+    Heating,
+    /// state `B` (on) is entered when the measurement rises to or above `upper`, and state
+    /// `A` (off) is entered when the measurement falls to or below `lower`—as with an air conditioner
+    Cooling,
+}
+
+/// hysteresis (deadband) bang-bang controller, driven by a measured process variable rather than
+/// by raw `bang()` toggles
+///
+/// The gap between `lower` and `upper` is the deadband: while the measurement sits strictly
+/// between the two bounds, [`update`](Hysteresis::update) makes no change, which is what stops a
+/// controller sitting right at a single threshold from rapidly cycling.
+///
+/// # Simple Example
+/// ```
+/// use bangbang::prelude::*;
+///
+/// fn example() -> Result<(), BangBangError> {
+///     // a heating controller (e.g. a furnace) that starts off, with a deadband between 18.0 and 21.0
+///     let mut controller = Hysteresis::new(false, 18.0, 21.0, HysteresisDirection::Heating, None, None);
+///     assert!(controller.is_off());
+///
+///     // the measurement falls to the lower bound, so the furnace turns on
+///     controller.update(18.0)?;
+///     assert!(controller.is_on());
+///
+///     // the measurement rises back into the deadband, so nothing happens
+///     controller.update(19.5)?;
+///     assert!(controller.is_on());
+///
+///     // the measurement rises to the upper bound, so the furnace turns back off
+///     controller.update(21.0)?;
+///     assert!(controller.is_off());
+///
+///     Ok(())
+/// }
+///
+/// example();
+/// ```
+#[derive(Default)]
+pub struct Hysteresis<'a> {
+    on: bool,
+    lower: f32,
+    upper: f32,
+    direction: HysteresisDirection,
+    handle_on: Option<&'a mut StateChangeHander>,
+    handle_off: Option<&'a mut StateChangeHander>,
+}
+
+impl fmt::Debug for Hysteresis<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Hysteresis {{ on: {}, lower: {}, upper: {}, direction: {:?} }}",
+            self.on, self.lower, self.upper, self.direction
+        )
+    }
+}
+
+impl BangBang for Hysteresis<'_> {
+    fn state(&self) -> BangBangState {
+        self.on.into()
+    }
+
+    fn set(&mut self, new_state: BangBangState) -> Result<(), BangBangError> {
+        #[cfg(feature = "boxed-source")]
+        let current_state = self.state();
+
+        let result = match new_state {
+            BangBangState::A => {
+                if let Some(handler) = &mut self.handle_off {
+                    handler()
+                } else {
+                    Ok(())
+                }
+            }
+            BangBangState::B => {
+                if let Some(handler) = &mut self.handle_on {
+                    handler()
+                } else {
+                    Ok(())
+                }
+            }
+        };
+        #[cfg(feature = "boxed-source")]
+        let result = result.map_err(|source| BangBangError::StateChangeHandlerFailed {
+            from: current_state,
+            to: new_state,
+            code: 0,
+            source: Some(source),
+        });
+        if result.is_ok() {
+            self.on = new_state != BangBangState::A;
+        }
+        result
+    }
+}
+
+impl<'a> Hysteresis<'a> {
+    /// creates a new hysteresis controller with the given deadband, direction, initial state, and
+    /// optional notification handlers for each state transition
+    /// ```
+    /// use bangbang::prelude::*;
+    ///
+    /// // create a cooling controller that starts off, with a deadband between 20.0 and 24.0
+    /// let hysteresis = Hysteresis::new(false, 20.0, 24.0, HysteresisDirection::Cooling, None, None);
+    /// assert!(hysteresis.is_off());
+    /// ```
+    pub fn new(
+        on: bool,
+        lower: f32,
+        upper: f32,
+        direction: HysteresisDirection,
+        handle_on: Option<&'a mut StateChangeHander>,
+        handle_off: Option<&'a mut StateChangeHander>,
+    ) -> Self {
+        Self {
+            on,
+            lower,
+            upper,
+            direction,
+            handle_on,
+            handle_off,
+        }
+    }
+
+    /// feeds a new measurement of the process variable to the controller, transitioning state
+    /// through [`BangBang::set`] (and so triggering the same handler and `log` behavior as
+    /// [`BangBang::bang`]) whenever the measurement has crossed the relevant bound
+    /// ```
+    /// use bangbang::prelude::*;
+    ///
+    /// fn example() -> Result<(), BangBangError> {
+    ///     let mut controller = Hysteresis::new(false, 18.0, 21.0, HysteresisDirection::Heating, None, None);
+    ///
+    ///     // still within the deadband, no change
+    ///     controller.update(19.0)?;
+    ///     assert!(controller.is_off());
+    ///
+    ///     // at or below the lower bound, the heating controller turns on
+    ///     controller.update(17.9)?;
+    ///     assert!(controller.is_on());
+    ///
+    ///     Ok(())
+    /// }
+    ///
+    /// example();
+    /// ```
+    pub fn update(&mut self, measurement: f32) -> Result<(), BangBangError> {
+        let current_state = self.state();
+
+        let new_state = match self.direction {
+            HysteresisDirection::Heating => {
+                if current_state == BangBangState::A && measurement <= self.lower {
+                    BangBangState::B
+                } else if current_state == BangBangState::B && measurement >= self.upper {
+                    BangBangState::A
+                } else {
+                    current_state
+                }
+            }
+            HysteresisDirection::Cooling => {
+                if current_state == BangBangState::A && measurement >= self.upper {
+                    BangBangState::B
+                } else if current_state == BangBangState::B && measurement <= self.lower {
+                    BangBangState::A
+                } else {
+                    current_state
+                }
+            }
+        };
+
+        if new_state == current_state {
+            return Ok(());
+        }
+
+        #[cfg(feature = "log")]
+        trace!(
+            "attempting state change from {:?} to {:?} at measurement {}",
+            current_state,
+            new_state,
+            measurement,
+        );
+
+        self.set(new_state)?;
+
+        #[cfg(feature = "log")]
+        debug!(
+            "state changed from {:?} to {:?}",
+            current_state,
+            self.state()
+        );
+
+        Ok(())
+    }
+
+    /// convienence method for checking if the controller is in the `on` state
+    /// ```
+    /// use bangbang::prelude::*;
+    ///
+    /// let hysteresis = Hysteresis::new(true, 18.0, 21.0, HysteresisDirection::Heating, None, None);
+    ///
+    /// // these two calls are equavalent
+    /// assert!(hysteresis.is_on());
+    /// assert_eq!(hysteresis.state(), BangBangState::B);
+    /// ```
+    pub fn is_on(&self) -> bool {
+        self.on
+    }
+
+    /// convienence method for checking if the controller is in the `off` state
+    /// ```
+    /// use bangbang::prelude::*;
+    ///
+    /// let hysteresis = Hysteresis::new(false, 18.0, 21.0, HysteresisDirection::Heating, None, None);
+    ///
+    /// // these two calls are equavalent
+    /// assert!(hysteresis.is_off());
+    /// assert_eq!(hysteresis.state(), BangBangState::A);
+    /// ```
+    pub fn is_off(&self) -> bool {
+        !self.on
+    }
+}
@@ -59,6 +59,11 @@
 //! | --- | --- | --- |
 //! | log | enabled | enables the [`log`] crate dependency and logging calls |
 //! | on-off | enabled | enables the [`OnOff`] reference implementation |
+//! | hysteresis | disabled | enables the [`Hysteresis`] deadband implementation |
+//! | debounce | disabled | enables the [`Debounced`] minimum dwell-time wrapper |
+//! | error-in-core | enabled | implements [`Display`](core::fmt::Display) and [`core::error::Error`] for [`BangBangError`], requires a toolchain where `core::error::Error` is stable |
+//! | boxed-source | disabled | pulls in `alloc` to let [`BangBangError::StateChangeHandlerFailed`] preserve a handler's domain error as its [`source()`](core::error::Error::source) |
+//! | composite | disabled | enables the [`Composite`] interlock controller |
 #![no_std]
 #![deny(warnings)]
 #![deny(bad_style)]
@@ -87,22 +92,70 @@
 #[cfg(feature = "log")]
 use log::{debug, trace};
 
+#[cfg(feature = "error-in-core")]
+use core::fmt;
+
+#[cfg(feature = "boxed-source")]
+extern crate alloc;
+
+/// the boxed trait object used by [`BangBangError::StateChangeHandlerFailed`] and by handler
+/// closures to carry a handler's own domain error; only defined when the `boxed-source` feature
+/// pulls in `alloc`
+#[cfg(feature = "boxed-source")]
+pub(crate) type BoxedSource = alloc::boxed::Box<dyn core::error::Error + Send + Sync + 'static>;
+
 #[cfg(feature = "on-off")]
 mod on_off;
 #[cfg(feature = "on-off")]
 pub use self::on_off::OnOff;
 
+#[cfg(feature = "hysteresis")]
+mod hysteresis;
+#[cfg(feature = "hysteresis")]
+pub use self::hysteresis::{Hysteresis, HysteresisDirection};
+
+#[cfg(feature = "debounce")]
+mod debounced;
+#[cfg(feature = "debounce")]
+pub use self::debounced::Debounced;
+
+#[cfg(feature = "composite")]
+mod composite;
+#[cfg(feature = "composite")]
+pub use self::composite::{Combinator, Composite};
+
 /// A convenience module appropriate for glob imports (`use bangbang::prelude::*;`)
 pub mod prelude {
     #[cfg(feature = "on-off")]
     #[doc(no_inline)]
     pub use super::on_off::OnOff;
+    #[cfg(feature = "hysteresis")]
+    #[doc(no_inline)]
+    pub use super::hysteresis::{Hysteresis, HysteresisDirection};
+    #[cfg(feature = "debounce")]
+    #[doc(no_inline)]
+    pub use super::debounced::Debounced;
+    #[cfg(feature = "composite")]
+    #[doc(no_inline)]
+    pub use super::composite::{Combinator, Composite};
     #[doc(no_inline)]
     pub use super::{BangBang, BangBangError, BangBangState};
 }
 
 /// bang-bang controller errors
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+///
+/// When the `boxed-source` feature is enabled, [`StateChangeHandlerFailed`](Self::StateChangeHandlerFailed)
+/// carries a boxed `dyn core::error::Error` so it can preserve a handler's own domain error; a
+/// boxed trait object can't implement `Clone`, `Copy`, `Eq`, `PartialEq`, `Ord`, or `Hash`, so this
+/// whole enum intentionally drops those derives for as long as that feature is on.
+#[derive(Debug)]
+#[cfg_attr(
+    not(feature = "boxed-source"),
+    derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)
+)]
+// a boxed handler source is only present behind `boxed-source`, and only then does this enum
+// need the heap or grow a pointer-sized field wider than its other variants
+#[cfg_attr(feature = "boxed-source", allow(box_pointers, variant_size_differences))]
 pub enum BangBangError {
     /// an unexpected error occured during state change
     StateChangeFailedUnexpectedly {
@@ -130,11 +183,97 @@ pub enum BangBangError {
         to: BangBangState,
         /// error code provided by the underlying implementation
         code: u8,
+        /// the handler's original error, recoverable via [`core::error::Error::source`]; only
+        /// present when the `boxed-source` feature is enabled
+        #[cfg(feature = "boxed-source")]
+        source: Option<BoxedSource>,
     },
     /// implementation handler unexpected error
     ImplementationHandlerUnexpectedError {},
 }
 
+#[cfg(feature = "error-in-core")]
+impl fmt::Display for BangBangError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BangBangError::StateChangeFailedUnexpectedly { from, to, code } => write!(
+                f,
+                "state change from {:?} to {:?} failed unexpectedly: code {}",
+                from, to, code
+            ),
+            BangBangError::StateChangeTemporarilyConstrained { from, to, code } => write!(
+                f,
+                "state change from {:?} to {:?} is temporarily constrained: code {}",
+                from, to, code
+            ),
+            BangBangError::StateChangeHandlerFailed {
+                from, to, code, ..
+            } => write!(
+                f,
+                "state change from {:?} to {:?} failed: code {}",
+                from, to, code
+            ),
+            BangBangError::ImplementationHandlerUnexpectedError {} => {
+                write!(f, "implementation handler encountered an unexpected error")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "error-in-core")]
+impl core::error::Error for BangBangError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            #[cfg(feature = "boxed-source")]
+            BangBangError::StateChangeHandlerFailed {
+                source: Some(boxed),
+                ..
+            } => Some(boxed.as_ref()),
+            #[cfg(feature = "boxed-source")]
+            BangBangError::StateChangeHandlerFailed { source: None, .. } => None,
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "boxed-source")]
+impl BangBangError {
+    /// builds a [`BangBangError::StateChangeHandlerFailed`] from a handler's own domain error,
+    /// preserving it as this error's [`source()`](core::error::Error::source) instead of
+    /// discarding it
+    /// ```
+    /// use bangbang::BangBangError;
+    /// use bangbang::BangBangState;
+    /// use core::error::Error;
+    ///
+    /// #[derive(Debug)]
+    /// struct RelayStuck;
+    ///
+    /// impl core::fmt::Display for RelayStuck {
+    ///     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    ///         write!(f, "relay stuck")
+    ///     }
+    /// }
+    ///
+    /// impl Error for RelayStuck {}
+    ///
+    /// let error =
+    ///     BangBangError::from_handler_error(BangBangState::A, BangBangState::B, 7, RelayStuck);
+    /// assert!(error.source().unwrap().downcast_ref::<RelayStuck>().is_some());
+    /// ```
+    pub fn from_handler_error<E>(from: BangBangState, to: BangBangState, code: u8, error: E) -> Self
+    where
+        E: core::error::Error + Send + Sync + 'static,
+    {
+        BangBangError::StateChangeHandlerFailed {
+            from,
+            to,
+            code,
+            source: Some(alloc::boxed::Box::new(error)),
+        }
+    }
+}
+
 /// bang-bang controller states
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub enum BangBangState {
@@ -144,8 +283,16 @@ pub enum BangBangState {
     B,
 }
 
-impl From<bool> for BangBangState {
-    fn from(value: bool) -> Self {
+impl BangBangState {
+    /// `const fn` equivalent of [`From<bool>`](BangBangState#impl-From<bool>-for-BangBangState),
+    /// for use in `const`/`static` contexts where the trait method isn't yet callable
+    /// ```
+    /// use bangbang::BangBangState;
+    ///
+    /// const ON: BangBangState = BangBangState::from_bool(true);
+    /// assert_eq!(ON, BangBangState::B);
+    /// ```
+    pub const fn from_bool(value: bool) -> Self {
         if value {
             BangBangState::B
         } else {
@@ -154,6 +301,12 @@ impl From<bool> for BangBangState {
     }
 }
 
+impl From<bool> for BangBangState {
+    fn from(value: bool) -> Self {
+        Self::from_bool(value)
+    }
+}
+
 /// abstraction of a bang-bang controller
 pub trait BangBang {
     /// returns the current state of the controller
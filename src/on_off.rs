@@ -1,9 +1,17 @@
 use super::prelude::*;
 use core::fmt;
 
-/// handler method to be called on a state change
+/// handler method to be called on a state change, reporting failure as a ready-made
+/// [`BangBangError`]
+#[cfg(not(feature = "boxed-source"))]
 type StateChangeHander = dyn FnMut() -> Result<(), BangBangError> + Sync + Send;
 
+/// handler method to be called on a state change, reporting failure as its own boxed domain
+/// error—`set()` wraps it into [`BangBangError::StateChangeHandlerFailed`] automatically,
+/// preserving it as that error's [`source()`](core::error::Error::source)
+#[cfg(feature = "boxed-source")]
+type StateChangeHander = dyn FnMut() -> Result<(), super::BoxedSource> + Sync + Send;
+
 /// simple on/off bang-bang controller
 ///
 /// # Simple Example
@@ -36,13 +44,20 @@ type StateChangeHander = dyn FnMut() -> Result<(), BangBangError> + Sync + Send;
 ///
 /// fn example() -> Result<(), BangBangError> {
 ///     // handler that always fails, `code` is a failure code that we can choose arbitrarily
+///     #[cfg(not(feature = "boxed-source"))]
 ///     let mut handle_on = || Err(BangBangError::StateChangeFailedUnexpectedly {
 ///         from: BangBangState::A,
 ///         to: BangBangState::B,
 ///         code: 1,
 ///     });
 ///
-///     // handler that always succeeds   
+///     // with `boxed-source` enabled, a handler instead reports its own boxed domain error,
+///     // which `set()` preserves as the resulting `BangBangError`'s `source()`
+///     #[cfg(feature = "boxed-source")]
+///     let mut handle_on =
+///         || -> Result<(), Box<dyn core::error::Error + Send + Sync>> { Err("stuck".into()) };
+///
+///     // handler that always succeeds
 ///     let mut handle_off = || Ok(());
 ///
 ///     // this controller defaults to the on state
@@ -86,6 +101,9 @@ impl BangBang for OnOff<'_> {
     }
 
     fn set(&mut self, new_state: BangBangState) -> Result<(), BangBangError> {
+        #[cfg(feature = "boxed-source")]
+        let current_state = self.state();
+
         let result = match new_state {
             BangBangState::A => {
                 if let Some(handler) = &mut self.handle_off {
@@ -102,6 +120,13 @@ impl BangBang for OnOff<'_> {
                 }
             }
         };
+        #[cfg(feature = "boxed-source")]
+        let result = result.map_err(|source| BangBangError::StateChangeHandlerFailed {
+            from: current_state,
+            to: new_state,
+            code: 0,
+            source: Some(source),
+        });
         if result.is_ok() {
             self.on = new_state != BangBangState::A;
         }
@@ -111,6 +136,9 @@ impl BangBang for OnOff<'_> {
 
 impl<'a> OnOff<'a> {
     /// creates a new on/off controller with optional notification handlers for each state transition
+    ///
+    /// This is a `const fn`, so a handler-less controller (`None`, `None`) can be assigned
+    /// straight into a `const` or `static` binding for compile-time embedded initialization.
     /// ```
     /// use bangbang::OnOff;
     ///
@@ -125,8 +153,12 @@ impl<'a> OnOff<'a> {
     /// // create a controller that starts in the on state
     /// let on_off = OnOff::new(true, Some(&mut handle_on), Some(&mut handle_off));
     /// assert!(on_off.is_on());
+    ///
+    /// // with no handlers, the controller can be declared as a compile-time constant
+    /// static STARTS_ON: OnOff<'static> = OnOff::new(true, None, None);
+    /// assert!(STARTS_ON.is_on());
     /// ```
-    pub fn new(
+    pub const fn new(
         on: bool,
         handle_on: Option<&'a mut StateChangeHander>,
         handle_off: Option<&'a mut StateChangeHander>,
@@ -148,7 +180,7 @@ impl<'a> OnOff<'a> {
     /// assert!(on_off.is_on());
     /// assert_eq!(on_off.state(), BangBangState::B);
     /// ```
-    pub fn is_on(&self) -> bool {
+    pub const fn is_on(&self) -> bool {
         self.on
     }
 
@@ -162,7 +194,7 @@ impl<'a> OnOff<'a> {
     /// assert!(on_off.is_off());
     /// assert_eq!(on_off.state(), BangBangState::A);
     /// ```
-    pub fn is_off(&self) -> bool {
+    pub const fn is_off(&self) -> bool {
         !self.on
     }
 }
@@ -0,0 +1,69 @@
+#[cfg(all(feature = "composite", feature = "on-off"))]
+use bangbang::prelude::*;
+
+#[cfg(all(feature = "composite", feature = "on-off"))]
+#[test]
+fn all_is_on_only_when_every_child_is_on() {
+    let mut a = OnOff::new(true, None, None);
+    let mut b = OnOff::new(true, None, None);
+    let mut composite = Composite::new([&mut a, &mut b], Combinator::All);
+    assert!(composite.is_on());
+
+    composite.children_mut()[1].bang().unwrap();
+    assert!(composite.is_off());
+}
+
+#[cfg(all(feature = "composite", feature = "on-off"))]
+#[test]
+fn any_is_on_when_at_least_one_child_is_on() {
+    let mut a = OnOff::new(false, None, None);
+    let mut b = OnOff::new(false, None, None);
+    let mut composite = Composite::new([&mut a, &mut b], Combinator::Any);
+    assert!(composite.is_off());
+
+    composite.children_mut()[0].bang().unwrap();
+    assert!(composite.is_on());
+}
+
+#[cfg(all(feature = "composite", feature = "on-off"))]
+#[test]
+fn set_forwards_to_every_child() {
+    let mut a = OnOff::new(false, None, None);
+    let mut b = OnOff::new(false, None, None);
+    let mut composite = Composite::new([&mut a, &mut b], Combinator::All);
+
+    assert!(composite.bang().is_ok());
+    assert!(composite.is_on());
+    assert_eq!(composite.children_mut()[0].state(), BangBangState::B);
+    assert_eq!(composite.children_mut()[1].state(), BangBangState::B);
+}
+
+#[cfg(all(feature = "composite", feature = "on-off", not(feature = "boxed-source")))]
+#[test]
+fn set_is_rejected_if_any_child_rejects_it() {
+    let mut handle_on = || {
+        Err(BangBangError::StateChangeFailedUnexpectedly {
+            from: BangBangState::A,
+            to: BangBangState::B,
+            code: 1,
+        })
+    };
+    let mut a = OnOff::new(false, Some(&mut handle_on), None);
+    let mut b = OnOff::new(false, None, None);
+    let mut composite = Composite::new([&mut a, &mut b], Combinator::All);
+
+    assert!(composite.bang().is_err());
+}
+
+#[cfg(all(feature = "composite", feature = "on-off", feature = "boxed-source"))]
+#[test]
+fn set_is_rejected_if_any_child_rejects_it() {
+    let mut handle_on = || -> Result<(), std::boxed::Box<dyn std::error::Error + Send + Sync>> {
+        Err("relay stuck".into())
+    };
+    let mut a = OnOff::new(false, Some(&mut handle_on), None);
+    let mut b = OnOff::new(false, None, None);
+    let mut composite = Composite::new([&mut a, &mut b], Combinator::All);
+
+    assert!(composite.bang().is_err());
+}
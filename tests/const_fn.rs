@@ -0,0 +1,24 @@
+#[cfg(feature = "on-off")]
+use bangbang::prelude::*;
+
+#[cfg(feature = "on-off")]
+static STATIC_ON_OFF: OnOff<'static> = OnOff::new(true, None, None);
+
+#[cfg(feature = "on-off")]
+const CONST_IS_ON: bool = STATIC_ON_OFF.is_on();
+
+#[cfg(feature = "on-off")]
+const CONST_STATE_FROM_TRUE: BangBangState = BangBangState::from_bool(true);
+
+// evaluated entirely at compile time: if `OnOff::new`, `is_on`, or `BangBangState::from_bool`
+// ever stop being `const fn`, this crate fails to build rather than just failing a test
+#[cfg(feature = "on-off")]
+const _: () = assert!(CONST_IS_ON);
+
+#[cfg(feature = "on-off")]
+#[test]
+fn on_off_and_its_accessors_are_usable_in_const_context() {
+    assert!(STATIC_ON_OFF.is_on());
+    assert!(!STATIC_ON_OFF.is_off());
+    assert_eq!(CONST_STATE_FROM_TRUE, BangBangState::B);
+}
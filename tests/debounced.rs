@@ -0,0 +1,57 @@
+#[cfg(all(feature = "debounce", feature = "on-off"))]
+use bangbang::prelude::*;
+#[cfg(all(feature = "debounce", feature = "on-off"))]
+use std::sync::atomic::{AtomicU64, Ordering};
+#[cfg(all(feature = "debounce", feature = "on-off"))]
+use std::sync::Arc;
+
+#[cfg(all(feature = "debounce", feature = "on-off"))]
+#[test]
+fn blocks_change_before_min_off_elapses_then_allows_it() {
+    let now = Arc::new(AtomicU64::new(0));
+    let now_for_clock = Arc::clone(&now);
+    let mut clock = move || now_for_clock.load(Ordering::Relaxed);
+
+    let on_off = OnOff::new(false, None, None);
+    let mut debounced = Debounced::new(on_off, 1000, 1000, &mut clock);
+    assert!(debounced.is_off());
+
+    // no time has passed since construction, so the transition is rejected
+    assert!(matches!(
+        debounced.bang(),
+        Err(BangBangError::StateChangeTemporarilyConstrained { .. })
+    ));
+    assert!(debounced.is_off());
+
+    // still not enough time has passed
+    now.store(500, Ordering::Relaxed);
+    assert!(debounced.bang().is_err());
+    assert!(debounced.is_off());
+
+    // now the minimum off-time has elapsed, so the transition succeeds
+    now.store(1000, Ordering::Relaxed);
+    assert!(debounced.bang().is_ok());
+    assert!(debounced.is_on());
+}
+
+#[cfg(all(feature = "debounce", feature = "on-off"))]
+#[test]
+fn blocks_change_before_min_on_elapses_then_allows_it() {
+    let now = Arc::new(AtomicU64::new(1000));
+    let now_for_clock = Arc::clone(&now);
+    let mut clock = move || now_for_clock.load(Ordering::Relaxed);
+
+    let on_off = OnOff::new(true, None, None);
+    let mut debounced = Debounced::new(on_off, 1000, 1000, &mut clock);
+    assert!(debounced.is_on());
+
+    assert!(matches!(
+        debounced.bang(),
+        Err(BangBangError::StateChangeTemporarilyConstrained { .. })
+    ));
+    assert!(debounced.is_on());
+
+    now.store(2000, Ordering::Relaxed);
+    assert!(debounced.bang().is_ok());
+    assert!(debounced.is_off());
+}
@@ -0,0 +1,66 @@
+#[cfg(feature = "error-in-core")]
+use bangbang::BangBangError;
+#[cfg(feature = "error-in-core")]
+use bangbang::BangBangState;
+
+#[cfg(feature = "error-in-core")]
+#[test]
+fn displays_state_change_failed_unexpectedly() {
+    let error = BangBangError::StateChangeFailedUnexpectedly {
+        from: BangBangState::A,
+        to: BangBangState::B,
+        code: 1,
+    };
+    assert_eq!(
+        error.to_string(),
+        "state change from A to B failed unexpectedly: code 1"
+    );
+}
+
+#[cfg(feature = "error-in-core")]
+#[test]
+fn displays_state_change_temporarily_constrained() {
+    let error = BangBangError::StateChangeTemporarilyConstrained {
+        from: BangBangState::B,
+        to: BangBangState::A,
+        code: 2,
+    };
+    assert_eq!(
+        error.to_string(),
+        "state change from B to A is temporarily constrained: code 2"
+    );
+}
+
+#[cfg(feature = "error-in-core")]
+#[test]
+fn displays_state_change_handler_failed() {
+    let error = BangBangError::StateChangeHandlerFailed {
+        from: BangBangState::A,
+        to: BangBangState::B,
+        code: 1,
+        #[cfg(feature = "boxed-source")]
+        source: None,
+    };
+    assert_eq!(
+        error.to_string(),
+        "state change from A to B failed: code 1"
+    );
+}
+
+#[cfg(feature = "error-in-core")]
+#[test]
+fn displays_implementation_handler_unexpected_error() {
+    let error = BangBangError::ImplementationHandlerUnexpectedError {};
+    assert_eq!(
+        error.to_string(),
+        "implementation handler encountered an unexpected error"
+    );
+}
+
+#[cfg(feature = "error-in-core")]
+#[test]
+fn implements_core_error() {
+    fn assert_error<E: core::error::Error>(_: &E) {}
+    let error = BangBangError::ImplementationHandlerUnexpectedError {};
+    assert_error(&error);
+}
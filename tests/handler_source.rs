@@ -0,0 +1,41 @@
+#[cfg(all(feature = "boxed-source", feature = "on-off"))]
+use bangbang::prelude::*;
+#[cfg(all(feature = "boxed-source", feature = "on-off"))]
+use core::error::Error;
+#[cfg(all(feature = "boxed-source", feature = "on-off"))]
+use core::fmt;
+
+#[cfg(all(feature = "boxed-source", feature = "on-off"))]
+#[derive(Debug)]
+struct RelayStuck {
+    code: u8,
+}
+
+#[cfg(all(feature = "boxed-source", feature = "on-off"))]
+impl fmt::Display for RelayStuck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "relay stuck: code {}", self.code)
+    }
+}
+
+#[cfg(all(feature = "boxed-source", feature = "on-off"))]
+impl Error for RelayStuck {}
+
+#[cfg(all(feature = "boxed-source", feature = "on-off"))]
+#[test]
+fn handler_error_is_recoverable_as_source() {
+    // the handler reports its own domain error directly (boxed); it never has to construct a
+    // `BangBangError` itself—`OnOff::set` does that wrapping automatically
+    let mut handle_on = || -> Result<(), Box<dyn Error + Send + Sync>> {
+        Err(Box::new(RelayStuck { code: 9 }))
+    };
+
+    let mut on_off = OnOff::new(false, Some(&mut handle_on), None);
+    let error = on_off.bang().unwrap_err();
+
+    let source = error.source().expect("handler error should be preserved");
+    let relay_stuck = source
+        .downcast_ref::<RelayStuck>()
+        .expect("source should downcast back to the original handler error");
+    assert_eq!(relay_stuck.code, 9);
+}
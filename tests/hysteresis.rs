@@ -0,0 +1,115 @@
+#[cfg(feature = "hysteresis")]
+use bangbang::prelude::*;
+
+#[cfg(feature = "hysteresis")]
+#[test]
+fn can_start_on() {
+    let hysteresis = Hysteresis::new(true, 18.0, 21.0, HysteresisDirection::Heating, None, None);
+    assert_eq!(hysteresis.is_on(), true);
+    assert_eq!(hysteresis.is_off(), false);
+}
+
+#[cfg(feature = "hysteresis")]
+#[test]
+fn can_start_off() {
+    let hysteresis = Hysteresis::new(false, 18.0, 21.0, HysteresisDirection::Heating, None, None);
+    assert_eq!(hysteresis.is_on(), false);
+    assert_eq!(hysteresis.is_off(), true);
+}
+
+#[cfg(feature = "hysteresis")]
+#[test]
+fn heating_turns_on_at_lower_and_off_at_upper() {
+    let mut hysteresis = Hysteresis::new(false, 18.0, 21.0, HysteresisDirection::Heating, None, None);
+
+    assert!(hysteresis.update(25.0).is_ok());
+    assert!(hysteresis.is_off());
+
+    assert!(hysteresis.update(18.0).is_ok());
+    assert!(hysteresis.is_on());
+
+    assert!(hysteresis.update(19.5).is_ok());
+    assert!(hysteresis.is_on());
+
+    assert!(hysteresis.update(21.0).is_ok());
+    assert!(hysteresis.is_off());
+}
+
+#[cfg(feature = "hysteresis")]
+#[test]
+fn cooling_turns_on_at_upper_and_off_at_lower() {
+    let mut hysteresis = Hysteresis::new(false, 20.0, 24.0, HysteresisDirection::Cooling, None, None);
+
+    assert!(hysteresis.update(15.0).is_ok());
+    assert!(hysteresis.is_off());
+
+    assert!(hysteresis.update(24.0).is_ok());
+    assert!(hysteresis.is_on());
+
+    assert!(hysteresis.update(22.0).is_ok());
+    assert!(hysteresis.is_on());
+
+    assert!(hysteresis.update(20.0).is_ok());
+    assert!(hysteresis.is_off());
+}
+
+#[cfg(feature = "hysteresis")]
+#[test]
+fn deadband_does_not_chatter_near_bounds() {
+    let mut hysteresis = Hysteresis::new(false, 18.0, 21.0, HysteresisDirection::Heating, None, None);
+
+    assert!(hysteresis.update(18.0).is_ok());
+    assert!(hysteresis.is_on());
+
+    // repeated measurements back at the lower bound should not turn the controller back off
+    assert!(hysteresis.update(18.0).is_ok());
+    assert!(hysteresis.is_on());
+    assert!(hysteresis.update(17.5).is_ok());
+    assert!(hysteresis.is_on());
+}
+
+#[cfg(feature = "hysteresis")]
+#[test]
+fn manual_bang_forces_a_flip() {
+    let mut hysteresis = Hysteresis::new(false, 18.0, 21.0, HysteresisDirection::Heating, None, None);
+
+    // a manual bang() forces a flip regardless of the measurement/deadband
+    assert!(hysteresis.bang().is_ok());
+    assert!(hysteresis.is_on());
+}
+
+#[cfg(feature = "hysteresis")]
+#[test]
+fn calls_handlers() {
+    use std::sync::Arc;
+    use std::sync::Mutex;
+
+    let called_on_handler = Arc::new(Mutex::new(false));
+    let called_on_inner_handler = Arc::clone(&called_on_handler);
+    let mut handle_on = move || {
+        *called_on_inner_handler.lock().unwrap() = true;
+        Ok(())
+    };
+
+    let called_off_handler = Arc::new(Mutex::new(false));
+    let called_off_inner_handler = Arc::clone(&called_off_handler);
+    let mut handle_off = move || {
+        *called_off_inner_handler.lock().unwrap() = true;
+        Ok(())
+    };
+
+    let mut hysteresis = Hysteresis::new(
+        false,
+        18.0,
+        21.0,
+        HysteresisDirection::Heating,
+        Some(&mut handle_on),
+        Some(&mut handle_off),
+    );
+
+    assert!(hysteresis.update(18.0).is_ok());
+    let called_on_handler = called_on_handler.lock().unwrap();
+    let called_off_handler = called_off_handler.lock().unwrap();
+    assert_eq!(*called_on_handler, true);
+    assert_eq!(*called_off_handler, false);
+}